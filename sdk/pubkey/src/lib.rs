@@ -1,6 +1,7 @@
 #![no_std]
 
 pub use five8_const::decode_32_const;
+pub use paste;
 pub use pinocchio;
 
 /// Convenience macro to define a static `Pubkey` value.
@@ -22,6 +23,50 @@ macro_rules! pubkey {
     };
 }
 
+/// Convenience macro to declare multiple compile-time `Pubkey` constants at once.
+///
+/// Each entry is validated at compile time exactly as [`pubkey!`] does today.
+/// Alongside the constants, a `known_ids()` function is generated that
+/// returns a `&'static [Pubkey]` slice of all of them, so callers can iterate
+/// or do membership checks without listing every constant by hand.
+///
+/// # Examples
+///
+/// ```rust
+/// use pinocchio_pubkey::pubkeys;
+///
+/// pubkeys! {
+///     SYSTEM_PROGRAM = "11111111111111111111111111111111";
+///     TOKEN_PROGRAM = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+/// }
+///
+/// assert!(known_ids().contains(&SYSTEM_PROGRAM));
+/// ```
+#[macro_export]
+macro_rules! pubkeys {
+    ( $( $name:ident = $id:literal );+ $(;)? ) => {
+        $(
+            #[doc = "A well-known compile-time `Pubkey` constant."]
+            pub const $name: $crate::pinocchio::pubkey::Pubkey = $crate::from_str($id);
+        )+
+
+        #[doc = "Returns all the pubkeys declared by this invocation of `pubkeys!`."]
+        #[inline]
+        pub fn known_ids() -> &'static [$crate::pinocchio::pubkey::Pubkey] {
+            &[ $( $name ),+ ]
+        }
+    };
+}
+
+/// Alias for [`pubkeys!`], for callers who prefer a name that mirrors
+/// [`declare_id!`].
+#[macro_export]
+macro_rules! declare_ids {
+    ( $( $tt:tt )+ ) => {
+        $crate::pubkeys! { $( $tt )+ }
+    };
+}
+
 /// Convenience macro to define a static `Pubkey` value representing the program ID.
 ///
 /// This macro also defines helper functions to check whether a given pubkey is
@@ -36,7 +81,9 @@ macro_rules! pubkey {
 /// 
 /// // Now you can use:
 /// // - ID: the program ID constant
+/// // - ID_BYTES: the program ID as a raw [u8; 32]
 /// // - check_id(&pubkey): returns true if pubkey matches the program ID
+/// // - is_id(&pubkey): `const fn` equivalent of check_id, usable in const contexts
 /// // - id(): returns the program ID
 /// ```
 #[macro_export]
@@ -45,12 +92,28 @@ macro_rules! declare_id {
         #[doc = "The const program ID."]
         pub const ID: $crate::pinocchio::pubkey::Pubkey = $crate::from_str($id);
 
+        #[doc = "The const program ID, as its raw bytes."]
+        pub const ID_BYTES: [u8; 32] = ID;
+
         #[doc = "Returns `true` if given pubkey is the program ID."]
         #[inline]
         pub fn check_id(id: &$crate::pinocchio::pubkey::Pubkey) -> bool {
             id == &ID
         }
 
+        #[doc = "Returns `true` if given pubkey is the program ID. `const fn` equivalent of `check_id`, usable in `const` contexts and `match` guards."]
+        #[inline]
+        pub const fn is_id(key: &$crate::pinocchio::pubkey::Pubkey) -> bool {
+            let mut i = 0;
+            while i < 32 {
+                if key[i] != ID[i] {
+                    return false;
+                }
+                i += 1;
+            }
+            true
+        }
+
         #[doc = "Returns the program ID."]
         #[inline]
         pub const fn id() -> $crate::pinocchio::pubkey::Pubkey {
@@ -59,6 +122,97 @@ macro_rules! declare_id {
     };
 }
 
+/// Convenience macro to declare a compile-time-seeded program-derived address.
+///
+/// Programs frequently derive PDAs from a fixed set of seeds plus their own
+/// program ID. This macro validates the seeds at compile time (each seed must
+/// be at most `MAX_SEED_LEN` (32) bytes, and there must be at most
+/// `MAX_SEEDS - 1` (15) of them, since `NAME_create`/`find_program_address`
+/// append one more seed for the bump and `MAX_SEEDS` (16) bounds that total),
+/// and then generates two functions built on the fixed seeds so callers can't
+/// mismatch them:
+///
+/// - `NAME() -> (Pubkey, u8)`: finds the canonical address and bump seed.
+/// - `NAME_create(bump: u8) -> Pubkey`: computes the address for a known bump.
+///
+/// # Panics
+///
+/// `NAME()`/`NAME_create()` call pinocchio's `find_program_address`/
+/// `create_program_address`, which are only available when actually running
+/// on-chain (`target_os = "solana"`) and panic unconditionally otherwise.
+/// Don't call them from off-chain code paths.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pinocchio_pubkey::{declare_id, declare_pda};
+///
+/// declare_id!("11111111111111111111111111111111");
+/// declare_pda!(vault, ID, b"vault");
+///
+/// let (address, bump) = vault();
+/// assert_eq!(vault_create(bump), address);
+/// ```
+#[macro_export]
+macro_rules! declare_pda {
+    ( $name:ident, $program_id:expr, $( $seed:expr ),+ $(,)? ) => {
+        const _: () = {
+            let seeds: &[&[u8]] = &[ $( $seed ),+ ];
+
+            let mut i = 0;
+            while i < seeds.len() {
+                if seeds[i].len() > $crate::pinocchio::pubkey::MAX_SEED_LEN {
+                    panic!("declare_pda!: seed exceeds MAX_SEED_LEN (32 bytes)");
+                }
+                i += 1;
+            }
+
+            // `NAME_create` (and `find_program_address` internally) appends
+            // one more seed for the bump, so the fixed seeds declared here
+            // must leave room for it under `MAX_SEEDS`.
+            if seeds.len() >= $crate::pinocchio::pubkey::MAX_SEEDS {
+                panic!("declare_pda!: too many seeds (max 15, one slot is reserved for the bump seed)");
+            }
+        };
+
+        $crate::paste::paste! {
+            #[doc = "Finds the canonical program-derived address and bump seed."]
+            #[inline]
+            pub fn $name() -> ($crate::pinocchio::pubkey::Pubkey, u8) {
+                $crate::pinocchio::pubkey::find_program_address(
+                    &[ $( $seed ),+ ],
+                    &$program_id,
+                )
+            }
+
+            #[doc = "Computes the program-derived address for a known bump seed."]
+            #[inline]
+            pub fn [<$name _create>](bump: u8) -> $crate::pinocchio::pubkey::Pubkey {
+                let bump_seed = [bump];
+                $crate::pinocchio::pubkey::create_program_address(
+                    &[ $( $seed, )+ &bump_seed ],
+                    &$program_id,
+                )
+                .expect("declare_pda!: invalid bump seed")
+            }
+        }
+    };
+}
+
+/// 16 fixed seeds would leave no room for the bump seed
+/// `find_program_address`/`create_program_address` append internally, so
+/// `declare_pda!` must reject it at compile time.
+///
+/// ```compile_fail
+/// pinocchio_pubkey::declare_id!("11111111111111111111111111111111");
+/// pinocchio_pubkey::declare_pda!(
+///     vault, ID, b"0", b"1", b"2", b"3", b"4", b"5", b"6", b"7", b"8", b"9", b"10", b"11",
+///     b"12", b"13", b"14", b"15"
+/// );
+/// ```
+#[allow(dead_code)]
+fn _doc_declare_pda_rejects_max_seeds() {}
+
 /// Create a `Pubkey` from a `&str` at compile time.
 /// 
 /// This function uses compile-time base58 decoding for maximum efficiency.
@@ -83,6 +237,127 @@ pub const fn from_str(value: &str) -> pinocchio::pubkey::Pubkey {
 /// Type alias for the Pubkey type for convenience.
 pub type Pubkey = pinocchio::pubkey::Pubkey;
 
+/// The maximum length of a base58-encoded 32-byte public key.
+const MAX_BASE58_LEN: usize = 44;
+
+/// The base58 alphabet used to encode and decode `Pubkey` values.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Errors that can occur when parsing a [`Pubkey`] from a base58 string at
+/// runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePubkeyError {
+    /// The decoded value is not exactly 32 bytes long.
+    WrongSize,
+    /// The string is not valid base58.
+    Invalid,
+}
+
+/// Parse a `Pubkey` from a base58-encoded string at runtime.
+///
+/// Unlike [`from_str`], the input does not need to be known at compile time
+/// and malformed input is reported as a [`ParsePubkeyError`] instead of
+/// causing a panic or compile error. This is useful for off-chain and
+/// `no_std` callers that receive a pubkey string at runtime, e.g. from
+/// instruction data, a config file, or an RPC response.
+///
+/// # Examples
+///
+/// ```rust
+/// use pinocchio_pubkey::try_from_str;
+///
+/// let pubkey = try_from_str("11111111111111111111111111111111").unwrap();
+/// assert_eq!(pubkey, [0u8; 32]);
+/// ```
+pub fn try_from_str(s: &str) -> Result<Pubkey, ParsePubkeyError> {
+    let input = s.as_bytes();
+    if input.is_empty() || input.len() > MAX_BASE58_LEN {
+        return Err(ParsePubkeyError::WrongSize);
+    }
+
+    let mut leading_zeros = 0;
+    while leading_zeros < input.len() && input[leading_zeros] == b'1' {
+        leading_zeros += 1;
+    }
+
+    let mut acc = [0u8; 32];
+    for &byte in &input[leading_zeros..] {
+        let digit = ALPHABET
+            .iter()
+            .position(|c| *c == byte)
+            .ok_or(ParsePubkeyError::Invalid)?;
+
+        let mut carry = digit as u32;
+        for b in acc.iter_mut().rev() {
+            carry += *b as u32 * 58;
+            *b = carry as u8;
+            carry >>= 8;
+        }
+        if carry != 0 {
+            return Err(ParsePubkeyError::WrongSize);
+        }
+    }
+
+    let used = 32 - acc.iter().take_while(|&&b| b == 0).count();
+    if leading_zeros + used != 32 {
+        return Err(ParsePubkeyError::WrongSize);
+    }
+
+    Ok(acc)
+}
+
+/// Encode a `Pubkey` as base58 into a stack-allocated buffer.
+///
+/// `out` must be at least 44 bytes long, the maximum base58 length of a
+/// 32-byte value, and the returned `&str` borrows from it. This lets `no_std`
+/// callers render a `Pubkey` for logging or error messages, and lets
+/// off-chain tools round-trip keys using only this crate, without pulling in
+/// `std` or `bs58`.
+///
+/// # Examples
+///
+/// ```rust
+/// use pinocchio_pubkey::{from_str, to_base58};
+///
+/// let key = from_str("11111111111111111111111111111111");
+/// let mut buf = [0u8; 44];
+/// assert_eq!(to_base58(&key, &mut buf), "11111111111111111111111111111111");
+/// ```
+pub fn to_base58<'a>(key: &Pubkey, out: &'a mut [u8; 44]) -> &'a str {
+    let mut digits = [0u8; 44];
+    let mut digits_len = 0usize;
+
+    for &byte in key.iter() {
+        let mut carry = byte as u32;
+        for d in digits[..digits_len].iter_mut() {
+            carry += (*d as u32) << 8;
+            *d = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits[digits_len] = (carry % 58) as u8;
+            digits_len += 1;
+            carry /= 58;
+        }
+    }
+
+    let leading_zeros = key.iter().take_while(|&&b| b == 0).count();
+
+    let mut len = 0;
+    for _ in 0..leading_zeros {
+        out[len] = b'1';
+        len += 1;
+    }
+    for &digit in digits[..digits_len].iter().rev() {
+        out[len] = ALPHABET[digit as usize];
+        len += 1;
+    }
+
+    // Every byte written above comes from `ALPHABET` or is `b'1'`, both of
+    // which are valid ASCII, so this can never fail.
+    core::str::from_utf8(&out[..len]).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,13 +372,120 @@ mod tests {
     #[test]
     fn test_declare_id_macro() {
         declare_id!("11111111111111111111111111111111");
-        
+
         let test_pubkey = from_str("11111111111111111111111111111111");
         assert!(check_id(&test_pubkey));
         assert_eq!(id(), test_pubkey);
-        
+        assert!(is_id(&test_pubkey));
+        assert_eq!(ID_BYTES, ID);
+
         // Use a different valid base58 pubkey for testing
         let other_pubkey = from_str("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
         assert!(!check_id(&other_pubkey));
+        assert!(!is_id(&other_pubkey));
+    }
+
+    #[test]
+    fn test_try_from_str_matches_const() {
+        let expected = from_str("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
+        let actual = try_from_str("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM").unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_try_from_str_all_zeros() {
+        let pubkey = try_from_str("11111111111111111111111111111111").unwrap();
+        assert_eq!(pubkey, [0u8; 32]);
+    }
+
+    #[test]
+    fn test_try_from_str_invalid_character() {
+        // '0', 'O', 'I', 'l' are not part of the base58 alphabet.
+        let err = try_from_str("0WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM").unwrap_err();
+        assert_eq!(err, ParsePubkeyError::Invalid);
+    }
+
+    #[test]
+    fn test_try_from_str_wrong_size() {
+        assert_eq!(try_from_str("1").unwrap_err(), ParsePubkeyError::WrongSize);
+        assert_eq!(try_from_str("").unwrap_err(), ParsePubkeyError::WrongSize);
+    }
+
+    // The macro's seed-length/seed-count checks are plain `const` assertions,
+    // so expanding it here is enough to exercise them on every target,
+    // without calling the generated functions (see below).
+    #[allow(dead_code)]
+    mod declare_pda_seed_validation {
+        use super::*;
+
+        declare_pda!(vault, ID, b"vault");
+        const ID: Pubkey = from_str("11111111111111111111111111111111");
+    }
+
+    // Pins the real seed-count limit: 15 fixed seeds must compile, since
+    // `NAME_create`/`find_program_address` append a 16th seed for the bump
+    // and `MAX_SEEDS` (16) bounds that total.
+    #[allow(dead_code)]
+    mod declare_pda_max_seeds {
+        use super::*;
+
+        declare_pda!(
+            vault, ID, b"0", b"1", b"2", b"3", b"4", b"5", b"6", b"7", b"8", b"9", b"10", b"11",
+            b"12", b"13", b"14"
+        );
+        const ID: Pubkey = from_str("11111111111111111111111111111111");
+    }
+
+    // `find_program_address`/`create_program_address` (used internally by
+    // the functions `declare_pda!` generates) are only available when
+    // actually running on-chain, so this is only compiled and exercised for
+    // that target. `target_os = "solana"` isn't a value upstream rustc knows
+    // about (it's added by the Solana toolchain's target spec), so it also
+    // needs `allow(unexpected_cfgs)` wherever it's checked directly like
+    // this, the same way the `pinocchio` crate itself does.
+    #[allow(unexpected_cfgs)]
+    #[cfg(target_os = "solana")]
+    declare_pda!(vault, ID_FOR_PDA_TEST, b"vault");
+
+    #[allow(unexpected_cfgs)]
+    #[cfg(target_os = "solana")]
+    const ID_FOR_PDA_TEST: Pubkey = from_str("11111111111111111111111111111111");
+
+    #[allow(unexpected_cfgs)]
+    #[cfg(target_os = "solana")]
+    #[test]
+    fn test_declare_pda_macro() {
+        let (address, bump) = vault();
+        assert_eq!(vault_create(bump), address);
+    }
+
+    #[test]
+    fn test_to_base58_all_zeros() {
+        let key = from_str("11111111111111111111111111111111");
+        let mut buf = [0u8; 44];
+        assert_eq!(
+            to_base58(&key, &mut buf),
+            "11111111111111111111111111111111"
+        );
+    }
+
+    #[test]
+    fn test_to_base58_round_trip() {
+        const EXPECTED: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+        let key = from_str(EXPECTED);
+        let mut buf = [0u8; 44];
+        assert_eq!(to_base58(&key, &mut buf), EXPECTED);
+        assert_eq!(try_from_str(to_base58(&key, &mut buf)).unwrap(), key);
+    }
+
+    pubkeys! {
+        PUBKEYS_TEST_A = "11111111111111111111111111111111";
+        PUBKEYS_TEST_B = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    }
+
+    #[test]
+    fn test_pubkeys_macro() {
+        assert_eq!(known_ids(), &[PUBKEYS_TEST_A, PUBKEYS_TEST_B]);
+        assert!(known_ids().contains(&PUBKEYS_TEST_A));
     }
 }
\ No newline at end of file